@@ -0,0 +1,99 @@
+use serde::Deserialize;
+use tui::style::{Color, Style};
+
+use crate::consts::CONFIG_PATH;
+
+/// A search backend to try, in the order listed in [`Config::providers`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProviderConfig {
+    /// The cookie-authenticated YouTube Music InnerTube API, read from
+    /// `headers.txt`.
+    YtMusic,
+    /// A public Invidious instance; no login required.
+    Invidious { instance: String },
+}
+
+fn default_providers() -> Vec<ProviderConfig> {
+    vec![
+        ProviderConfig::YtMusic,
+        ProviderConfig::Invidious {
+            instance: "https://invidious.snopyta.org".to_string(),
+        },
+    ]
+}
+
+#[derive(Debug, Clone)]
+pub struct PlayerConfig {
+    pub text_next_style: Style,
+    pub text_queued_style: Style,
+    pub text_downloading_style: Style,
+    pub text_done_style: Style,
+    pub text_failed_style: Style,
+}
+
+impl Default for PlayerConfig {
+    fn default() -> Self {
+        Self {
+            text_next_style: Style::default().fg(Color::Gray),
+            text_queued_style: Style::default().fg(Color::DarkGray),
+            text_downloading_style: Style::default().fg(Color::Yellow),
+            text_done_style: Style::default().fg(Color::Green),
+            text_failed_style: Style::default().fg(Color::Red),
+        }
+    }
+}
+
+fn default_max_parallel_downloads() -> usize {
+    4
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    /// Search providers, tried in order until one answers successfully.
+    #[serde(default = "default_providers")]
+    pub providers: Vec<ProviderConfig>,
+    /// How many videos `systems::download` will fetch at once.
+    #[serde(default = "default_max_parallel_downloads")]
+    pub max_parallel_downloads: usize,
+    /// Grab the smallest audio-only stream instead of a video one.
+    #[serde(default)]
+    pub audio_only: bool,
+    /// Preferred video height in pixels, e.g. `720`. Ignored when
+    /// `audio_only` is set. The closest available stream is picked when
+    /// the exact resolution isn't offered.
+    #[serde(default)]
+    pub target_resolution: Option<u32>,
+    /// Save raw API responses that parse to nothing under
+    /// `CACHE_DIR/reports/`, for offline debugging of parser drift. Off
+    /// by default so normal users pay nothing for it.
+    #[serde(default)]
+    pub enable_parse_reports: bool,
+    #[serde(skip)]
+    pub player: PlayerConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            providers: default_providers(),
+            max_parallel_downloads: default_max_parallel_downloads(),
+            audio_only: false,
+            target_resolution: None,
+            enable_parse_reports: false,
+            player: PlayerConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads `config.json` from the working directory, falling back to
+    /// defaults (cookie-based search with an Invidious fallback) if it's
+    /// missing or malformed.
+    pub fn load() -> Self {
+        std::fs::read_to_string(CONFIG_PATH)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+}
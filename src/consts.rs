@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use once_cell::sync::Lazy;
+
+use crate::config::Config;
+
+pub const CONFIG_PATH: &str = "config.json";
+
+pub const HEADER_TUTORIAL: &str = "\
+To use YTerMusic with your YouTube Music account, open music.youtube.com \
+in your browser, open the network tab of the developer tools, pick any \
+request to `music.youtube.com`, copy its request headers and paste them \
+into a `headers.txt` file next to the executable.
+If you'd rather not log in, YTerMusic will fall back to a public \
+Invidious instance instead.";
+
+pub static CACHE_DIR: Lazy<PathBuf> = Lazy::new(|| {
+    dirs::cache_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("ytermusic")
+});
+
+pub static CONFIG: Lazy<Config> = Lazy::new(Config::load);
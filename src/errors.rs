@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+/// Severity of a failure surfaced from the search/download pipeline. Lets
+/// callers decide whether to retry automatically, show a dismissible
+/// warning, or treat it as something the user has to fix themselves,
+/// instead of every `Err` arm just being logged and forgotten.
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    /// Worth retrying automatically, e.g. a timed-out `api.search`.
+    Transient(String),
+    /// Part of a batch failed but the rest is still usable, e.g. one
+    /// playlist out of several couldn't be browsed.
+    Recoverable(String),
+    /// Nothing useful can be done without user intervention, e.g. a
+    /// malformed `headers.txt`.
+    Fatal(String),
+}
+
+impl Outcome {
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Transient(m) | Self::Recoverable(m) | Self::Fatal(m) => m,
+        }
+    }
+
+    /// Whether this outcome's underlying operation is worth retrying
+    /// automatically.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Transient(_))
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            Self::Transient(_) => "transient",
+            Self::Recoverable(_) => "recoverable",
+            Self::Fatal(_) => "fatal",
+        };
+        write!(f, "[{kind}] {}", self.message())
+    }
+}
+
+impl Outcome {
+    /// Classifies a [`ytpapi::Error`] by reference, for call sites that
+    /// still need the error afterwards (e.g. to decide whether to retry).
+    pub fn from_ytpapi(e: &ytpapi::Error) -> Self {
+        match e {
+            ytpapi::Error::Reqwest(e) if e.is_timeout() || e.is_connect() => {
+                Self::Transient(format!("network error: {e}"))
+            }
+            ytpapi::Error::InvalidHeaders(e) => {
+                Self::Fatal(format!("invalid `headers.txt`: {e}"))
+            }
+            other => Self::Recoverable(other.to_string()),
+        }
+    }
+}
+
+impl From<ytpapi::Error> for Outcome {
+    fn from(e: ytpapi::Error) -> Self {
+        Self::from_ytpapi(&e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Outcome;
+
+    #[test]
+    fn invalid_headers_is_fatal() {
+        let e = ytpapi::Error::InvalidHeaders("missing `Cookie` header".to_string());
+        assert!(matches!(Outcome::from_ytpapi(&e), Outcome::Fatal(_)));
+    }
+
+    #[test]
+    fn anything_else_is_recoverable() {
+        let e = ytpapi::Error::Io(std::io::Error::new(std::io::ErrorKind::NotFound, "nope"));
+        assert!(matches!(Outcome::from_ytpapi(&e), Outcome::Recoverable(_)));
+    }
+
+    #[test]
+    fn is_transient_only_matches_the_transient_variant() {
+        assert!(Outcome::Transient("x".to_string()).is_transient());
+        assert!(!Outcome::Recoverable("x".to_string()).is_transient());
+        assert!(!Outcome::Fatal("x".to_string()).is_transient());
+    }
+}
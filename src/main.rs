@@ -100,7 +100,10 @@ async fn app_start() -> Result<(), Error> {
     tasks::local_musics::spawn_local_musics_task(updater_s.clone());
 
     STARTUP_TIME.log("Running manager");
-    let mut manager = Manager::new(sa, player).await;
+    // `updater_s` also goes to `Manager` itself now, so screens like
+    // `Search` can push a `Notify` back onto the same queue used by the
+    // background tasks above, instead of only logging failures.
+    let mut manager = Manager::new(sa, player, updater_s.clone()).await;
     manager.run(&updater_r).unwrap();
     Ok(())
 }
@@ -0,0 +1,2 @@
+pub mod performance;
+pub mod sound_action;
@@ -0,0 +1,23 @@
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+
+/// Marks the moment the process started, so startup stages can be logged
+/// relative to it.
+pub static STARTUP_TIME: Lazy<Performance> = Lazy::new(Performance::new);
+
+pub struct Performance {
+    start: Instant,
+}
+
+impl Performance {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+
+    pub fn log(&self, step: &str) {
+        eprintln!("[{:>8.2?}] {step}", self.start.elapsed());
+    }
+}
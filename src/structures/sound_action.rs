@@ -0,0 +1,32 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use once_cell::sync::Lazy;
+use ytpapi::Video;
+
+/// Where a video is in the download pipeline, surfaced to the UI so
+/// `Status::Unknown`'s render style can reflect "queued" vs "downloading"
+/// vs "done" instead of staying static until the file just appears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadStatus {
+    Queued,
+    Downloading,
+    Done,
+    Failed,
+}
+
+/// Latest known status of every video that's gone through
+/// `tasks::download`, keyed by `Video::video_id`. Updated by
+/// `tasks::download::start_task_unary` when a video is queued and by
+/// `tasks::download::DownloadTask` as it progresses, read by
+/// `Status::render_style` to pick a style without needing to thread any
+/// extra context through `ListItemAction`.
+pub static DOWNLOAD_STATUSES: Lazy<RwLock<HashMap<String, DownloadStatus>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone)]
+pub enum SoundAction {
+    /// Queues a video for playback and local download.
+    AddVideoUnary(Video),
+    /// A download enqueued via `tasks::download` changed state.
+    DownloadStatusChanged(String, DownloadStatus),
+}
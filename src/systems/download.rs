@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use flume::Sender;
+use futures::StreamExt;
+
+use crate::{
+    consts::CONFIG, structures::sound_action::SoundAction, tasks::download::DOWNLOAD_QUEUE,
+};
+
+/// Drains the global download queue through a pool bounded by
+/// `config.max_parallel_downloads`, so enqueueing a whole playlist fans
+/// its videos out across N simultaneous `rustube` fetches instead of
+/// running them one at a time.
+pub fn spawn_system(_action_sender: Arc<Sender<SoundAction>>) {
+    tokio::task::spawn(async move {
+        DOWNLOAD_QUEUE
+            .1
+            .clone()
+            .into_stream()
+            // `for_each_concurrent` treats a limit of 0 as no limit at all,
+            // so a misconfigured 0 would start every queued download at
+            // once instead of bounding the pool. Clamp it up to 1.
+            .for_each_concurrent(CONFIG.max_parallel_downloads.max(1), |task| task.run())
+            .await;
+    });
+}
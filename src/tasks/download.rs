@@ -0,0 +1,111 @@
+use std::{str::FromStr, sync::Arc};
+
+use flume::{Receiver, Sender};
+use once_cell::sync::Lazy;
+use rustube::{Id, Video as RustubeVideo};
+use ytpapi::Video;
+
+use crate::{
+    consts::{CACHE_DIR, CONFIG},
+    structures::sound_action::{DownloadStatus, SoundAction, DOWNLOAD_STATUSES},
+    systems::logger::log_,
+};
+
+/// Global queue of videos waiting to be downloaded. `start_task_unary`
+/// only enqueues onto it; `systems::download::spawn_system` is what drains
+/// it through a bounded worker pool.
+pub static DOWNLOAD_QUEUE: Lazy<(Sender<DownloadTask>, Receiver<DownloadTask>)> =
+    Lazy::new(flume::unbounded);
+
+pub struct DownloadTask {
+    pub video: Video,
+    pub action_sender: Arc<Sender<SoundAction>>,
+}
+
+impl DownloadTask {
+    fn report(&self, status: DownloadStatus) {
+        DOWNLOAD_STATUSES
+            .write()
+            .unwrap()
+            .insert(self.video.video_id.clone(), status);
+        self.action_sender
+            .send(SoundAction::DownloadStatusChanged(
+                self.video.video_id.clone(),
+                status,
+            ))
+            .ok();
+    }
+
+    /// Downloads this task's video, honoring the `audio_only`/resolution
+    /// selection from `config`, and reports its progress back to the UI
+    /// as it goes.
+    pub async fn run(self) {
+        self.report(DownloadStatus::Downloading);
+        match self.fetch().await {
+            Ok(()) => self.report(DownloadStatus::Done),
+            Err(e) => {
+                log_(format!(
+                    "download of {} failed: {e:?}",
+                    self.video.video_id
+                ));
+                self.report(DownloadStatus::Failed);
+            }
+        }
+    }
+
+    async fn fetch(&self) -> Result<(), rustube::Error> {
+        let id = Id::from_str(&self.video.video_id)?;
+        let descrambler = RustubeVideo::from_id(id.into_owned()).await?;
+        let streams = descrambler.streams();
+        let stream = if CONFIG.audio_only {
+            streams
+                .iter()
+                .filter(|s| s.includes_audio_track && !s.includes_video_track)
+                .min_by_key(|s| s.content_length.unwrap_or(u64::MAX))
+        } else {
+            let target = CONFIG.target_resolution;
+            streams
+                .iter()
+                .filter(|s| s.includes_video_track)
+                .min_by_key(|s| match (s.height, target) {
+                    (Some(height), Some(target)) => (height as i64 - target as i64).abs(),
+                    _ => 0,
+                })
+        }
+        .ok_or(rustube::Error::NoStreams)?;
+        let path = CACHE_DIR
+            .join("downloads")
+            .join(format!("{}.mp4", self.video.video_id));
+        stream.download_to(path).await
+    }
+}
+
+/// Enqueues a single video for download on the bounded worker pool.
+pub fn start_task_unary(action_sender: Arc<Sender<SoundAction>>, video: Video) {
+    DOWNLOAD_STATUSES
+        .write()
+        .unwrap()
+        .insert(video.video_id.clone(), DownloadStatus::Queued);
+    action_sender
+        .send(SoundAction::DownloadStatusChanged(
+            video.video_id.clone(),
+            DownloadStatus::Queued,
+        ))
+        .ok();
+    DOWNLOAD_QUEUE
+        .0
+        .send(DownloadTask {
+            video,
+            action_sender,
+        })
+        .ok();
+}
+
+/// Enqueues every video of a playlist at once. The worker pool fans them
+/// out across `config.max_parallel_downloads` simultaneous downloads
+/// instead of the caller having to drive them one at a time.
+pub fn start_task_playlist(action_sender: Arc<Sender<SoundAction>>, videos: Vec<Video>) {
+    for video in videos {
+        start_task_unary(action_sender.clone(), video);
+    }
+}
@@ -0,0 +1,113 @@
+use std::{sync::Arc, time::Duration};
+
+use flume::Sender;
+use serde::{Deserialize, Serialize};
+use ytpapi::FeedVideo;
+
+use crate::{consts::CACHE_DIR, systems::logger::log_, term::ManagerMessage};
+
+const SUBSCRIPTIONS_FILE: &str = "subscriptions.json";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// A channel the user has subscribed to; its public Atom feed is polled
+/// for new uploads instead of going through InnerTube.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Channel {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Subscriptions {
+    #[serde(default)]
+    pub channels: Vec<Channel>,
+}
+
+fn subscriptions_path() -> std::path::PathBuf {
+    CACHE_DIR.join(SUBSCRIPTIONS_FILE)
+}
+
+/// Loads the persisted subscription list, defaulting to empty if it
+/// doesn't exist yet or is malformed.
+pub fn load_subscriptions() -> Subscriptions {
+    std::fs::read_to_string(subscriptions_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_subscriptions(subs: &Subscriptions) {
+    if let Ok(json) = serde_json::to_string_pretty(subs) {
+        let _ = std::fs::create_dir_all(&*CACHE_DIR);
+        let _ = std::fs::write(subscriptions_path(), json);
+    }
+}
+
+/// Subscribes to a channel, a no-op if it's already in the list.
+pub fn add_channel(id: String, name: String) {
+    let mut subs = load_subscriptions();
+    if subs.channels.iter().any(|c| c.id == id) {
+        return;
+    }
+    subs.channels.push(Channel { id, name });
+    save_subscriptions(&subs);
+}
+
+pub fn remove_channel(id: &str) {
+    let mut subs = load_subscriptions();
+    subs.channels.retain(|c| c.id != id);
+    save_subscriptions(&subs);
+}
+
+/// Updates a subscribed channel's display name in place, a no-op if it
+/// isn't subscribed. Used to backfill the real channel name once its feed
+/// has been fetched at least once: `add_channel` is called from the UI
+/// with only the raw ID the user typed, before any feed data exists.
+fn rename_channel(id: &str, name: &str) {
+    let mut subs = load_subscriptions();
+    if let Some(channel) = subs.channels.iter_mut().find(|c| c.id == id) {
+        if channel.name != name {
+            channel.name = name.to_string();
+            save_subscriptions(&subs);
+        }
+    }
+}
+
+/// Periodically refetches every subscribed channel's feed and reports the
+/// newest uploads to the UI, the same way `tasks::last_playlist` keeps
+/// the last-played playlist fresh in the background.
+pub fn spawn_subscriptions_task(updater_s: Arc<Sender<ManagerMessage>>) {
+    tokio::task::spawn(async move {
+        loop {
+            let subs = load_subscriptions();
+            let mut feed_videos: Vec<FeedVideo> = Vec::new();
+            for channel in &subs.channels {
+                match ytpapi::fetch_channel_feed(&channel.id).await {
+                    Ok(mut new_videos) => {
+                        // `add_channel` only has the raw ID to go on; once
+                        // a feed fetch succeeds, backfill the real name it
+                        // parsed from the Atom entries' `author`.
+                        if channel.name == channel.id {
+                            if let Some(name) = new_videos.first().map(|fv| fv.video.author.clone())
+                            {
+                                rename_channel(&channel.id, &name);
+                            }
+                        }
+                        feed_videos.append(&mut new_videos);
+                    }
+                    Err(e) => log_(format!(
+                        "failed to refresh subscription feed for {}: {:?}",
+                        channel.name, e
+                    )),
+                }
+            }
+            // Sort on the feed's own publish timestamp, newest first.
+            feed_videos.sort_by(|a, b| b.published.cmp(&a.published));
+            let videos = feed_videos.into_iter().map(|fv| fv.video).collect();
+            updater_s
+                .send(ManagerMessage::SubscriptionsUpdated(videos))
+                .ok();
+            tokio::time::sleep(REFRESH_INTERVAL).await;
+        }
+    });
+}
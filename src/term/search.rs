@@ -1,7 +1,10 @@
 use std::{
     path::PathBuf,
     str::FromStr,
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
 };
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -14,11 +17,18 @@ use tui::{
     Frame,
 };
 use urlencoding::encode;
-use ytpapi::{Playlist, Video, YTApi};
+use ytpapi::{InvidiousApi, Playlist, SearchProvider, Video, YTApi};
 
 use crate::{
-    consts::CONFIG, run_service, structures::sound_action::SoundAction, systems::logger::log_,
-    tasks, utils::invert, DATABASE,
+    config::ProviderConfig,
+    consts::{CACHE_DIR, CONFIG},
+    errors::Outcome,
+    run_service,
+    structures::sound_action::{DownloadStatus, SoundAction, DOWNLOAD_STATUSES},
+    systems::logger::log_,
+    tasks,
+    utils::invert,
+    DATABASE,
 };
 
 use super::{
@@ -27,13 +37,189 @@ use super::{
     split_y_start, EventResponse, ManagerMessage, Screen, Screens,
 };
 
+/// The public instance used as a last resort, both when no configured
+/// provider works at construction time and, when `CONFIG.providers` lists
+/// no Invidious instance, as the runtime fallback for a `YtMusic` provider
+/// whose cookies are no longer valid.
+const DEFAULT_INVIDIOUS_INSTANCE: &str = "https://invidious.snopyta.org";
+
+/// The search backend actually in use. Kept as a concrete enum rather
+/// than `Arc<dyn SearchProvider>` so the YTApi variant can still expose
+/// continuation-token pagination, which the trait doesn't carry.
+///
+/// `YtMusic` always carries an Invidious fallback alongside the cookie-
+/// authenticated client: a `headers.txt` that parsed fine at startup can
+/// still get every later request rejected (expired cookies, a revoked
+/// session), so the app needs to be usable without cookies in that case
+/// too, not just when `headers.txt` is missing outright.
+pub enum Provider {
+    YtMusic {
+        primary: Arc<YTApi>,
+        fallback: Arc<InvidiousApi>,
+    },
+    Invidious(Arc<InvidiousApi>),
+}
+
+impl Provider {
+    /// Builds the first provider from `CONFIG.providers` that's actually
+    /// usable, logging and skipping over ones that aren't (e.g. no
+    /// `headers.txt`, or a malformed one) so the app stays usable without
+    /// cookies.
+    async fn from_config() -> Arc<Self> {
+        let fallback_instance = CONFIG
+            .providers
+            .iter()
+            .find_map(|provider| match provider {
+                ProviderConfig::Invidious { instance } => Some(instance.clone()),
+                ProviderConfig::YtMusic => None,
+            })
+            .unwrap_or_else(|| DEFAULT_INVIDIOUS_INSTANCE.to_string());
+        for provider in &CONFIG.providers {
+            match provider {
+                ProviderConfig::YtMusic => {
+                    match YTApi::from_header_file(
+                        PathBuf::from_str("headers.txt").unwrap().as_path(),
+                    )
+                    .await
+                    {
+                        Ok(mut api) => {
+                            if CONFIG.enable_parse_reports {
+                                api = api.with_reports(CACHE_DIR.join("reports"));
+                            }
+                            return Arc::new(Self::YtMusic {
+                                primary: Arc::new(api),
+                                fallback: Arc::new(InvidiousApi::new(fallback_instance)),
+                            });
+                        }
+                        Err(e) => log_(format!("YtMusic provider unavailable: {:?}", e)),
+                    }
+                }
+                ProviderConfig::Invidious { instance } => {
+                    return Arc::new(Self::Invidious(Arc::new(InvidiousApi::new(
+                        instance.clone(),
+                    ))));
+                }
+            }
+        }
+        // No configured provider worked; fall back to the public default
+        // instance so the app is never left without search at all.
+        Arc::new(Self::Invidious(Arc::new(InvidiousApi::new(
+            DEFAULT_INVIDIOUS_INSTANCE,
+        ))))
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+    ) -> Result<((Vec<Video>, Vec<Playlist>), Option<String>), ytpapi::Error> {
+        match self {
+            Self::YtMusic { primary, fallback } => match primary.search(query).await {
+                Ok(page) => Ok(page),
+                Err(primary_err) => SearchProvider::search(fallback.as_ref(), query)
+                    .await
+                    .map(|page| (page, None))
+                    .map_err(|_| primary_err),
+            },
+            Self::Invidious(api) => SearchProvider::search(api.as_ref(), query)
+                .await
+                .map(|page| (page, None)),
+        }
+    }
+
+    async fn browse_playlist(
+        &self,
+        id: &str,
+    ) -> Result<(Vec<Video>, Option<String>), ytpapi::Error> {
+        match self {
+            Self::YtMusic { primary, fallback } => match primary.browse_playlist(id).await {
+                Ok(page) => Ok(page),
+                Err(primary_err) => SearchProvider::browse_playlist(fallback.as_ref(), id)
+                    .await
+                    .map(|videos| (videos, None))
+                    .map_err(|_| primary_err),
+            },
+            Self::Invidious(api) => SearchProvider::browse_playlist(api.as_ref(), id)
+                .await
+                .map(|videos| (videos, None)),
+        }
+    }
+
+    /// Fetches the next search page. Pagination only ever continues
+    /// against the backend that started it: the Invidious fallback
+    /// doesn't hand out continuation tokens in the first place, so there's
+    /// nothing to fall back *to* here.
+    async fn search_continuation(
+        &self,
+        token: &str,
+    ) -> Result<((Vec<Video>, Vec<Playlist>), Option<String>), ytpapi::Error> {
+        match self {
+            Self::YtMusic { primary, .. } => primary.search_continuation(token).await,
+            Self::Invidious(_) => Ok(((Vec::new(), Vec::new()), None)),
+        }
+    }
+
+    /// Fetches the next page of a playlist browse. Same caveat as
+    /// [`Self::search_continuation`]: only ever reached for the YtMusic
+    /// backend, since Invidious never hands out a token.
+    async fn browse_playlist_continuation(
+        &self,
+        token: &str,
+    ) -> Result<(Vec<Video>, Option<String>), ytpapi::Error> {
+        match self {
+            Self::YtMusic { primary, .. } => primary.browse_playlist_continuation(token).await,
+            Self::Invidious(_) => Ok((Vec::new(), None)),
+        }
+    }
+}
+
 pub struct Search {
     pub text: String,
     pub goto: Screens,
     pub list: Arc<RwLock<ListItem<Status>>>,
     pub search_handle: Option<JoinHandle<()>>,
-    pub api: Option<Arc<ytpapi::YTApi>>,
+    pub api: Arc<Provider>,
     pub action_sender: Arc<Sender<SoundAction>>,
+    /// Where failures from the background search/browse tasks are reported
+    /// so they show up as something other than a line in the log file.
+    pub notify_sender: Arc<Sender<ManagerMessage>>,
+    /// Whether a `load_next_page` request is already in flight. Can't reuse
+    /// `search_handle` for this: it's only ever cleared by `.take()` when
+    /// the query text changes, so once a search had run once, treating its
+    /// presence as "a page load is in flight" made every later page load a
+    /// permanent no-op.
+    paging: Arc<AtomicBool>,
+    /// Continuation token for the next page of the current search, `None`
+    /// once either the search hasn't run yet or the results are exhausted.
+    search_token: Arc<RwLock<Option<String>>>,
+}
+
+/// Runs a search, retrying once after a short backoff if the failure looks
+/// [`Outcome::Transient`] (e.g. a timed-out request). Every failure is
+/// reported through `notify`, including the retry's, so the user always
+/// sees why a search came back empty instead of it just looking stuck.
+async fn search_with_retry(
+    api: &Provider,
+    query: &str,
+    notify: &Sender<ManagerMessage>,
+) -> Result<((Vec<Video>, Vec<Playlist>), Option<String>), ytpapi::Error> {
+    let first = api.search(query).await;
+    let Err(e) = &first else {
+        return first;
+    };
+    let outcome = Outcome::from_ytpapi(e);
+    let retry = outcome.is_transient();
+    notify.send(ManagerMessage::Notify(outcome)).ok();
+    if !retry {
+        return first;
+    }
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    let second = api.search(query).await;
+    if let Err(e) = &second {
+        notify
+            .send(ManagerMessage::Notify(Outcome::from_ytpapi(e)))
+            .ok();
+    }
+    second
 }
 #[derive(Clone, Debug, PartialEq)]
 pub enum Status {
@@ -45,7 +231,13 @@ impl ListItemAction for Status {
     fn render_style(&self, _: &str, selected: bool) -> Style {
         let k = match self {
             Self::Local(_) => CONFIG.player.text_next_style,
-            Self::Unknown(_) => CONFIG.player.text_downloading_style,
+            Self::Unknown(video) => match DOWNLOAD_STATUSES.read().unwrap().get(&video.video_id) {
+                Some(DownloadStatus::Queued) => CONFIG.player.text_queued_style,
+                Some(DownloadStatus::Downloading) => CONFIG.player.text_downloading_style,
+                Some(DownloadStatus::Failed) => CONFIG.player.text_failed_style,
+                Some(DownloadStatus::Done) => CONFIG.player.text_done_style,
+                None => Style::default(),
+            },
             Self::PlayList(_, _) => CONFIG.player.text_next_style,
         };
         if selected {
@@ -82,6 +274,11 @@ impl Screen for Search {
         if let Some(e) = self.list.write().unwrap().on_key_press(key) {
             return self.execute_status(e.clone(), key.modifiers);
         }
+        if matches!(key.code, KeyCode::Down | KeyCode::PageDown)
+            && self.list.read().unwrap().is_near_bottom()
+        {
+            self.load_next_page();
+        }
         let textbefore = self.text.trim().to_owned();
         match key.code {
             KeyCode::Delete | KeyCode::Backspace => {
@@ -99,6 +296,10 @@ impl Screen for Search {
         if let Some(handle) = self.search_handle.take() {
             handle.abort();
         }
+        // A fresh query invalidates any in-flight page load against the
+        // old one.
+        self.paging.store(false, Ordering::SeqCst);
+        *self.search_token.write().unwrap() = None;
 
         let text = self.text.to_lowercase();
 
@@ -115,15 +316,21 @@ impl Screen for Search {
             .collect::<Vec<_>>();
         self.list.write().unwrap().update_contents(local.clone());
 
-        if let Some(api) = self.api.clone() {
+        {
+            let api = self.api.clone();
             let text = self.text.clone();
             let items = self.list.clone();
+            let search_token = self.search_token.clone();
+            let notify_sender = self.notify_sender.clone();
             self.search_handle = Some(run_service(async move {
                 // Sleep to prevent spamming the api
                 tokio::time::sleep(std::time::Duration::from_millis(300)).await;
                 let mut item = Vec::new();
-                match api.search(&encode(&text).replace("%20", "+")).await {
-                    Ok((e, p)) => {
+                match search_with_retry(&api, &encode(&text).replace("%20", "+"), &notify_sender)
+                    .await
+                {
+                    Ok(((e, p), next_token)) => {
+                        *search_token.write().unwrap() = next_token;
                         for video in e.into_iter() {
                             let id = video.video_id.clone();
                             item.push((
@@ -138,32 +345,65 @@ impl Screen for Search {
                         for playlist in p.into_iter() {
                             let api = api.clone();
                             let items = items.clone();
+                            let notify_sender = notify_sender.clone();
                             run_service(async move {
-                                match api.browse_playlist(&playlist.browse_id).await {
-                                    Ok(e) => {
-                                        if e.is_empty() {
+                                let (mut videos, mut token) =
+                                    match api.browse_playlist(&playlist.browse_id).await {
+                                        Ok(page) => page,
+                                        Err(e) => {
+                                            // One playlist failing to browse
+                                            // shouldn't stop the others from
+                                            // showing up, so this is always
+                                            // Recoverable rather than fatal.
+                                            notify_sender
+                                                .send(ManagerMessage::Notify(
+                                                    Outcome::Recoverable(e.to_string()),
+                                                ))
+                                                .ok();
                                             return;
                                         }
-                                        items.write().unwrap().add_element((
-                                            format_playlist(
-                                                &format!(
-                                                    " [P] {} ({})",
-                                                    playlist.name, playlist.subtitle
-                                                ),
-                                                &e,
-                                            ),
-                                            Status::PlayList(playlist, e),
-                                        ));
-                                    }
-                                    Err(e) => {
-                                        log_(format!("{:?}", e));
+                                    };
+                                if videos.is_empty() {
+                                    return;
+                                }
+                                // Keep pulling continuation pages so large
+                                // playlists load incrementally instead of
+                                // needing one huge first response.
+                                while let Some(next) = token {
+                                    tokio::time::sleep(std::time::Duration::from_millis(300))
+                                        .await;
+                                    match api.browse_playlist_continuation(&next).await {
+                                        Ok((mut more, next_token)) => {
+                                            if more.is_empty() {
+                                                break;
+                                            }
+                                            videos.append(&mut more);
+                                            token = next_token;
+                                        }
+                                        Err(e) => {
+                                            notify_sender
+                                                .send(ManagerMessage::Notify(Outcome::from(e)))
+                                                .ok();
+                                            break;
+                                        }
                                     }
-                                };
+                                }
+                                items.write().unwrap().add_element((
+                                    format_playlist(
+                                        &format!(
+                                            " [P] {} ({})",
+                                            playlist.name, playlist.subtitle
+                                        ),
+                                        &videos,
+                                    ),
+                                    Status::PlayList(playlist, videos),
+                                ));
                             });
                         }
                     }
-                    Err(e) => {
-                        log_(format!("{:?}", e));
+                    Err(_) => {
+                        // Already reported (and, if transient, retried) by
+                        // `search_with_retry`.
                     }
                 }
                 let mut local = local;
@@ -208,7 +448,10 @@ impl Screen for Search {
     }
 }
 impl Search {
-    pub async fn new(action_sender: Arc<Sender<SoundAction>>) -> Self {
+    pub async fn new(
+        action_sender: Arc<Sender<SoundAction>>,
+        notify_sender: Arc<Sender<ManagerMessage>>,
+    ) -> Self {
         Self {
             text: String::new(),
             list: Arc::new(RwLock::new(ListItem::new(
@@ -216,12 +459,65 @@ impl Search {
             ))),
             goto: Screens::MusicPlayer,
             search_handle: None,
-            api: YTApi::from_header_file(PathBuf::from_str("headers.txt").unwrap().as_path())
-                .await
-                .ok()
-                .map(Arc::new),
+            api: Provider::from_config().await,
             action_sender,
+            notify_sender,
+            paging: Arc::new(AtomicBool::new(false)),
+            search_token: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Fetches the next page of the current search using the continuation
+    /// token left by the previous page, appending the results in place.
+    /// A no-op if a page load is already in flight or the previous page was
+    /// the last one.
+    fn load_next_page(&mut self) {
+        if self.paging.swap(true, Ordering::SeqCst) {
+            return;
         }
+        let api = self.api.clone();
+        let Some(token) = self.search_token.read().unwrap().clone() else {
+            self.paging.store(false, Ordering::SeqCst);
+            return;
+        };
+        let items = self.list.clone();
+        let search_token = self.search_token.clone();
+        let notify_sender = self.notify_sender.clone();
+        let paging = self.paging.clone();
+        run_service(async move {
+            // Sleep to prevent spamming the api
+            tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+            match api.search_continuation(&token).await {
+                Ok(((videos, _), next_token)) => {
+                    {
+                        let mut current = search_token.write().unwrap();
+                        // A fresh query replaced the token while this page
+                        // was loading, so these results are for the old one.
+                        if current.as_deref() != Some(token.as_str()) {
+                            return;
+                        }
+                        *current = next_token;
+                    }
+                    for video in videos {
+                        let id = video.video_id.clone();
+                        items.write().unwrap().add_element((
+                            format!(" {video} "),
+                            if DATABASE.read().unwrap().iter().any(|x| x.video_id == id) {
+                                Status::Local(video)
+                            } else {
+                                Status::Unknown(video)
+                            },
+                        ));
+                    }
+                }
+                Err(e) => {
+                    notify_sender
+                        .send(ManagerMessage::Notify(Outcome::from(e)))
+                        .ok();
+                }
+            }
+            paging.store(false, Ordering::SeqCst);
+        });
     }
 
     pub fn execute_status(&self, e: Status, modifiers: KeyModifiers) -> EventResponse {
@@ -229,7 +525,7 @@ impl Search {
             Status::Local(e) | Status::Unknown(e) => {
                 self.action_sender
                     .send(SoundAction::AddVideoUnary(e.clone()))
-                    .unwrap();
+                    .ok();
                 tasks::download::start_task_unary(self.action_sender.clone(), e);
                 if modifiers.contains(KeyModifiers::CONTROL) {
                     EventResponse::None
@@ -237,9 +533,25 @@ impl Search {
                     ManagerMessage::PlayerFrom(Screens::Playlist).event()
                 }
             }
-            Status::PlayList(e, v) => ManagerMessage::Inspect(e.name, Screens::Search, v)
-                .pass_to(Screens::PlaylistViewer)
-                .event(),
+            Status::PlayList(e, v) => {
+                if modifiers.contains(KeyModifiers::CONTROL) {
+                    // Ctrl+Enter on a playlist queues every video at once
+                    // onto the bounded downloader pool, the same way
+                    // Ctrl+Enter on a single video queues it without
+                    // leaving the search screen.
+                    for video in &v {
+                        self.action_sender
+                            .send(SoundAction::AddVideoUnary(video.clone()))
+                            .ok();
+                    }
+                    tasks::download::start_task_playlist(self.action_sender.clone(), v);
+                    EventResponse::None
+                } else {
+                    ManagerMessage::Inspect(e.name, Screens::Search, v)
+                        .pass_to(Screens::PlaylistViewer)
+                        .event()
+                }
+            }
         }
     }
 }
@@ -0,0 +1,158 @@
+use std::sync::{Arc, RwLock};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use flume::Sender;
+use tui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+use crate::{
+    structures::sound_action::SoundAction,
+    tasks::{self, subscriptions::add_channel},
+    DATABASE,
+};
+
+use super::{
+    item_list::ListItem, search::Status, split_y_start, EventResponse, ManagerMessage, Screen,
+    Screens,
+};
+
+/// Lists the newest uploads across subscribed channels, refreshed in the
+/// background by `tasks::subscriptions::spawn_subscriptions_task`. Typing
+/// a channel ID and pressing Enter subscribes to it; selecting a video
+/// plays/downloads it through the same flow as `Search`.
+pub struct Subscriptions {
+    pub text: String,
+    pub goto: Screens,
+    pub list: Arc<RwLock<ListItem<Status>>>,
+    pub action_sender: Arc<Sender<SoundAction>>,
+}
+
+impl Screen for Subscriptions {
+    fn on_mouse_press(
+        &mut self,
+        mouse_event: crossterm::event::MouseEvent,
+        frame_data: &Rect,
+    ) -> EventResponse {
+        let splitted = split_y_start(*frame_data, 3);
+        if let Some(e) = self
+            .list
+            .write()
+            .unwrap()
+            .on_mouse_press(mouse_event, &splitted[1])
+        {
+            self.execute_status(e, mouse_event.modifiers)
+        } else {
+            EventResponse::None
+        }
+    }
+
+    fn on_key_press(&mut self, key: KeyEvent, _: &Rect) -> EventResponse {
+        if KeyCode::Esc == key.code {
+            return ManagerMessage::ChangeState(self.goto).event();
+        }
+        if let Some(e) = self.list.write().unwrap().on_key_press(key) {
+            return self.execute_status(e.clone(), key.modifiers);
+        }
+        match key.code {
+            KeyCode::Delete | KeyCode::Backspace => {
+                self.text.pop();
+            }
+            KeyCode::Char(a) => {
+                self.text.push(a);
+            }
+            KeyCode::Enter => {
+                let channel_id = self.text.trim().to_string();
+                if !channel_id.is_empty() {
+                    add_channel(channel_id.clone(), channel_id);
+                    self.text.clear();
+                }
+            }
+            _ => {}
+        }
+        EventResponse::None
+    }
+
+    fn render(&mut self, frame: &mut Frame<tui::backend::CrosstermBackend<std::io::Stdout>>) {
+        let splitted = split_y_start(frame.size(), 3);
+        frame.render_widget(
+            Paragraph::new(self.text.clone())
+                .style(Style::default().fg(Color::LightCyan))
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .style(Style::default().fg(Color::White))
+                        .title(" Add a channel ID, Enter to subscribe ")
+                        .border_type(BorderType::Plain),
+                ),
+            splitted[0],
+        );
+        let items = self.list.read().unwrap();
+        frame.render_widget(&*items, splitted[1]);
+    }
+
+    fn handle_global_message(&mut self, message: ManagerMessage) -> EventResponse {
+        if let ManagerMessage::SubscriptionsUpdated(videos) = message {
+            let items = videos
+                .into_iter()
+                .map(|video| {
+                    let id = video.video_id.clone();
+                    (
+                        format!(" {video} "),
+                        if DATABASE.read().unwrap().iter().any(|x| x.video_id == id) {
+                            Status::Local(video)
+                        } else {
+                            Status::Unknown(video)
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            self.list.write().unwrap().update_contents(items);
+        }
+        EventResponse::None
+    }
+
+    fn close(&mut self, _: Screens) -> EventResponse {
+        EventResponse::None
+    }
+
+    fn open(&mut self) -> EventResponse {
+        EventResponse::None
+    }
+}
+
+impl Subscriptions {
+    pub fn new(action_sender: Arc<Sender<SoundAction>>) -> Self {
+        Self {
+            text: String::new(),
+            list: Arc::new(RwLock::new(ListItem::new(
+                "Newest uploads from your subscriptions".to_string(),
+            ))),
+            goto: Screens::MusicPlayer,
+            action_sender,
+        }
+    }
+
+    pub fn execute_status(&self, e: Status, modifiers: KeyModifiers) -> EventResponse {
+        match e {
+            Status::Local(e) | Status::Unknown(e) => {
+                self.action_sender
+                    .send(SoundAction::AddVideoUnary(e.clone()))
+                    .ok();
+                tasks::download::start_task_unary(self.action_sender.clone(), e);
+                if modifiers.contains(KeyModifiers::CONTROL) {
+                    EventResponse::None
+                } else {
+                    ManagerMessage::PlayerFrom(Screens::Playlist).event()
+                }
+            }
+            Status::PlayList(e, v) => ManagerMessage::Inspect(e.name, Screens::Subscriptions, v)
+                .pass_to(Screens::PlaylistViewer)
+                .event(),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use serde::Deserialize;
+
+use crate::{Error, Video};
+
+const FEED_URL: &str = "https://www.youtube.com/feeds/videos.xml";
+
+#[derive(Debug, Deserialize)]
+struct Feed {
+    #[serde(rename = "entry", default)]
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Entry {
+    // The feed declares this element in the `yt:` namespace
+    // (`<yt:videoId>`); quick-xml's serde deserializer surfaces namespaced
+    // tags with the prefix still attached rather than resolving it away.
+    #[serde(rename = "yt:videoId")]
+    video_id: String,
+    title: String,
+    author: Author,
+    published: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Author {
+    name: String,
+}
+
+/// A single upload from a channel's feed, paired with its publish
+/// timestamp. Kept separate from `Video` rather than stuffed into
+/// `album`, since that field already means something else for
+/// local-library and search-derived videos.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedVideo {
+    pub video: Video,
+    pub published: String,
+}
+
+/// Fetches and parses a channel's public Atom feed
+/// (`/feeds/videos.xml?channel_id=...`), no InnerTube call involved. Used
+/// to back subscriptions without needing a login.
+pub async fn fetch_channel_feed(channel_id: &str) -> Result<Vec<FeedVideo>, Error> {
+    let body = reqwest::get(format!("{FEED_URL}?channel_id={channel_id}"))
+        .await
+        .map_err(Error::Reqwest)?
+        .text()
+        .await
+        .map_err(Error::Reqwest)?;
+    let feed: Feed = quick_xml::de::from_str(&body).map_err(|e| Error::Feed(e.to_string()))?;
+    Ok(feed
+        .entries
+        .into_iter()
+        .map(|entry| FeedVideo {
+            published: entry.published,
+            video: Video {
+                video_id: entry.video_id,
+                title: entry.title,
+                author: entry.author.name,
+                album: String::new(),
+                duration: String::new(),
+            },
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Feed;
+
+    // Trimmed from a real `/feeds/videos.xml?channel_id=...` response;
+    // keeps the `yt:`-namespaced fields that trip up a naive rename.
+    const SAMPLE_FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns:yt="http://www.youtube.com/xml/schemas/2015" xmlns:media="http://search.yahoo.com/mrss/" xmlns="http://www.w3.org/2005/Atom">
+ <link rel="self" href="http://www.youtube.com/feeds/videos.xml?channel_id=UC123"/>
+ <id>yt:channel:UC123</id>
+ <yt:channelId>UC123</yt:channelId>
+ <title>Some Channel</title>
+ <author>
+  <name>Some Channel</name>
+  <uri>https://www.youtube.com/channel/UC123</uri>
+ </author>
+ <published>2023-01-01T00:00:00+00:00</published>
+ <entry>
+  <id>yt:video:abc123</id>
+  <yt:videoId>abc123</yt:videoId>
+  <yt:channelId>UC123</yt:channelId>
+  <title>A video title</title>
+  <link rel="alternate" href="https://www.youtube.com/watch?v=abc123"/>
+  <author>
+   <name>Some Channel</name>
+   <uri>https://www.youtube.com/channel/UC123</uri>
+  </author>
+  <published>2023-02-03T12:00:00+00:00</published>
+  <updated>2023-02-03T12:00:00+00:00</updated>
+ </entry>
+</feed>"#;
+
+    #[test]
+    fn parses_videoid_title_author_and_published_from_a_real_feed_shape() {
+        let feed: Feed = quick_xml::de::from_str(SAMPLE_FEED).unwrap();
+        assert_eq!(feed.entries.len(), 1);
+        let entry = &feed.entries[0];
+        assert_eq!(entry.video_id, "abc123");
+        assert_eq!(entry.title, "A video title");
+        assert_eq!(entry.author.name, "Some Channel");
+        assert_eq!(entry.published, "2023-02-03T12:00:00+00:00");
+    }
+
+    #[test]
+    fn tolerates_a_channel_with_no_uploads() {
+        let feed: Feed = quick_xml::de::from_str(
+            r#"<feed xmlns:yt="http://www.youtube.com/xml/schemas/2015" xmlns="http://www.w3.org/2005/Atom">
+ <title>Empty Channel</title>
+</feed>"#,
+        )
+        .unwrap();
+        assert!(feed.entries.is_empty());
+    }
+}
@@ -0,0 +1,368 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client,
+};
+use serde_json::{json, Value};
+
+pub use feed::{fetch_channel_feed, FeedVideo};
+pub use reports::{replay_report, ReportConfig};
+pub use structs::{Playlist, Video};
+use structs::{from_json, get_continuation_token, get_playlist_search, get_video};
+
+mod feed;
+mod reports;
+mod structs;
+
+const SEARCH_ENDPOINT: &str = "https://music.youtube.com/youtubei/v1/search";
+const BROWSE_ENDPOINT: &str = "https://music.youtube.com/youtubei/v1/browse";
+
+/// A backend capable of answering searches and playlist browses with a
+/// `Video`/`Playlist` pair, regardless of how it talks to YouTube (or
+/// doesn't). Lets the app fall back to a backend that needs no login when
+/// the cookie-authenticated one isn't available.
+#[async_trait]
+pub trait SearchProvider: Send + Sync {
+    async fn search(&self, query: &str) -> Result<(Vec<Video>, Vec<Playlist>), Error>;
+    async fn browse_playlist(&self, id: &str) -> Result<Vec<Video>, Error>;
+}
+
+#[async_trait]
+impl SearchProvider for YTApi {
+    async fn search(&self, query: &str) -> Result<(Vec<Video>, Vec<Playlist>), Error> {
+        self.search(query).await.map(|(page, _)| page)
+    }
+
+    async fn browse_playlist(&self, id: &str) -> Result<Vec<Video>, Error> {
+        self.browse_playlist(id).await.map(|(videos, _)| videos)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Reqwest(reqwest::Error),
+    SerdeJson(serde_json::Error),
+    Io(std::io::Error),
+    InvalidHeaders(String),
+    InvalidReport(String),
+    Feed(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reqwest(e) => write!(f, "reqwest error: {e}"),
+            Self::SerdeJson(e) => write!(f, "json error: {e}"),
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::InvalidHeaders(e) => write!(f, "invalid headers: {e}"),
+            Self::InvalidReport(e) => write!(f, "invalid report: {e}"),
+            Self::Feed(e) => write!(f, "feed error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The InnerTube client context sent with every request, along with the
+/// user's cookies, which is all this API needs to impersonate a browser.
+pub struct YTApi {
+    client: Client,
+    headers: HeaderMap,
+    context: Value,
+    report: Option<ReportConfig>,
+}
+
+impl YTApi {
+    /// Reads a Netscape-style `headers.txt` (one `Key: Value` header per
+    /// line, as copied from the browser's network tab) and builds an API
+    /// client from it.
+    pub async fn from_header_file(path: &Path) -> Result<Self, Error> {
+        let content = tokio::fs::read_to_string(path).await.map_err(Error::Io)?;
+        let mut headers = HeaderMap::new();
+        for line in content.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let name = HeaderName::from_bytes(key.trim().as_bytes())
+                .map_err(|e| Error::InvalidHeaders(e.to_string()))?;
+            let value = HeaderValue::from_str(value.trim())
+                .map_err(|e| Error::InvalidHeaders(e.to_string()))?;
+            headers.insert(name, value);
+        }
+        if !headers.contains_key("cookie") {
+            return Err(Error::InvalidHeaders(
+                "missing `Cookie` header".to_string(),
+            ));
+        }
+        Ok(Self {
+            client: Client::new(),
+            headers,
+            context: json!({
+                "context": {
+                    "client": {
+                        "clientName": "WEB_REMIX",
+                        "clientVersion": "1.20230213.01.00",
+                    }
+                }
+            }),
+            report: None,
+        })
+    }
+
+    /// Opts into writing raw responses that came back empty to `dir`, for
+    /// later replay with [`replay_report`]. Off by default.
+    pub fn with_reports(mut self, dir: PathBuf) -> Self {
+        self.report = Some(ReportConfig { dir });
+        self
+    }
+
+    fn context_with(&self, extra: HashMap<&str, Value>) -> Value {
+        let mut body = self.context.clone();
+        let object = body.as_object_mut().expect("context is always an object");
+        for (key, value) in extra {
+            object.insert(key.to_string(), value);
+        }
+        body
+    }
+
+    async fn post(&self, url: &str, body: Value) -> Result<Value, Error> {
+        self.client
+            .post(url)
+            .headers(self.headers.clone())
+            .json(&body)
+            .send()
+            .await
+            .map_err(Error::Reqwest)?
+            .json::<Value>()
+            .await
+            .map_err(Error::Reqwest)
+    }
+
+    /// `report_on_empty` should only ever be set for a *first* page: an
+    /// empty continuation page is the normal end-of-pagination signal, not
+    /// a parse failure, and reporting it would fill the report directory
+    /// with noise on every exhausted paging chain.
+    fn extract_page(
+        &self,
+        extractor: &str,
+        json: &Value,
+        report_on_empty: bool,
+    ) -> Result<((Vec<Video>, Vec<Playlist>), Option<String>), Error> {
+        let videos = from_json(json, get_video)?;
+        let playlists = from_json(json, get_playlist_search)?;
+        let token = from_json(json, get_continuation_token)?.into_iter().next();
+        if report_on_empty && videos.is_empty() && playlists.is_empty() {
+            reports::maybe_report(self.report.as_ref(), extractor, json);
+        }
+        Ok(((videos, playlists), token))
+    }
+
+    /// Runs a search and returns its first page together with a
+    /// continuation token usable with [`Self::search_continuation`] to
+    /// fetch the next page (`None` once the results are exhausted).
+    pub async fn search(
+        &self,
+        query: &str,
+    ) -> Result<((Vec<Video>, Vec<Playlist>), Option<String>), Error> {
+        let json = self
+            .post(
+                SEARCH_ENDPOINT,
+                self.context_with(HashMap::from([("query", json!(query))])),
+            )
+            .await?;
+        self.extract_page("search", &json, true)
+    }
+
+    /// Fetches the next page of a search started with [`Self::search`],
+    /// using the token it returned.
+    pub async fn search_continuation(
+        &self,
+        token: &str,
+    ) -> Result<((Vec<Video>, Vec<Playlist>), Option<String>), Error> {
+        let json = self
+            .post(
+                SEARCH_ENDPOINT,
+                self.context_with(HashMap::from([("continuation", json!(token))])),
+            )
+            .await?;
+        self.extract_page("search_continuation", &json, false)
+    }
+
+    /// Browses a playlist's first page of videos, along with a
+    /// continuation token to fetch the rest incrementally.
+    pub async fn browse_playlist(&self, id: &str) -> Result<(Vec<Video>, Option<String>), Error> {
+        let json = self
+            .post(
+                BROWSE_ENDPOINT,
+                self.context_with(HashMap::from([("browseId", json!(format!("VL{id}")))])),
+            )
+            .await?;
+        let videos = from_json(&json, get_video)?;
+        let token = from_json(&json, get_continuation_token)?.into_iter().next();
+        if videos.is_empty() {
+            reports::maybe_report(self.report.as_ref(), "browse_playlist", &json);
+        }
+        Ok((videos, token))
+    }
+
+    /// Fetches the next page of a playlist started with
+    /// [`Self::browse_playlist`]. An empty page here is the normal
+    /// end-of-pagination signal, not a parse failure, so unlike
+    /// `browse_playlist` it never writes a report.
+    pub async fn browse_playlist_continuation(
+        &self,
+        token: &str,
+    ) -> Result<(Vec<Video>, Option<String>), Error> {
+        let json = self
+            .post(
+                BROWSE_ENDPOINT,
+                self.context_with(HashMap::from([("continuation", json!(token))])),
+            )
+            .await?;
+        let videos = from_json(&json, get_video)?;
+        let token = from_json(&json, get_continuation_token)?.into_iter().next();
+        Ok((videos, token))
+    }
+}
+
+/// A [`SearchProvider`] backed by a public Invidious instance. Needs no
+/// cookies, at the cost of no continuation-token pagination: Invidious
+/// returns a single page of results for search and playlists alike.
+pub struct InvidiousApi {
+    client: Client,
+    instance: String,
+}
+
+impl InvidiousApi {
+    pub fn new(instance: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            instance: instance.into(),
+        }
+    }
+
+    async fn get_json(&self, path: &str) -> Result<Value, Error> {
+        self.client
+            .get(format!("{}{path}", self.instance))
+            .send()
+            .await
+            .map_err(Error::Reqwest)?
+            .json::<Value>()
+            .await
+            .map_err(Error::Reqwest)
+    }
+}
+
+fn format_duration(seconds: u64) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn invidious_video(value: &Value) -> Option<Video> {
+    Some(Video {
+        video_id: value.get("videoId")?.as_str()?.to_string(),
+        title: value.get("title")?.as_str()?.to_string(),
+        author: value
+            .get("author")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        album: String::new(),
+        duration: value
+            .get("lengthSeconds")
+            .and_then(Value::as_u64)
+            .map(format_duration)
+            .unwrap_or_default(),
+    })
+}
+
+#[async_trait]
+impl SearchProvider for InvidiousApi {
+    async fn search(&self, query: &str) -> Result<(Vec<Video>, Vec<Playlist>), Error> {
+        let json = self
+            .get_json(&format!(
+                "/api/v1/search?q={}",
+                urlencoding::encode(query)
+            ))
+            .await?;
+        let mut videos = Vec::new();
+        let mut playlists = Vec::new();
+        for item in json.as_array().into_iter().flatten() {
+            match item.get("type").and_then(Value::as_str) {
+                Some("video") => videos.extend(invidious_video(item)),
+                Some("playlist") => {
+                    if let (Some(playlist_id), Some(title)) = (
+                        item.get("playlistId").and_then(Value::as_str),
+                        item.get("title").and_then(Value::as_str),
+                    ) {
+                        playlists.push(Playlist {
+                            name: title.to_string(),
+                            subtitle: item
+                                .get("author")
+                                .and_then(Value::as_str)
+                                .unwrap_or_default()
+                                .to_string(),
+                            browse_id: playlist_id.to_string(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok((videos, playlists))
+    }
+
+    async fn browse_playlist(&self, id: &str) -> Result<Vec<Video>, Error> {
+        let json = self.get_json(&format!("/api/v1/playlists/{id}")).await?;
+        Ok(json
+            .get("videos")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(invidious_video)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::invidious_video;
+    use serde_json::json;
+
+    #[test]
+    fn parses_a_video_with_all_fields_present() {
+        let value = json!({
+            "videoId": "abc123",
+            "title": "A video title",
+            "author": "Some Channel",
+            "lengthSeconds": 125,
+        });
+        let video = invidious_video(&value).unwrap();
+        assert_eq!(video.video_id, "abc123");
+        assert_eq!(video.title, "A video title");
+        assert_eq!(video.author, "Some Channel");
+        assert_eq!(video.duration, "2:05");
+    }
+
+    #[test]
+    fn tolerates_a_missing_author_and_duration() {
+        let value = json!({
+            "videoId": "abc123",
+            "title": "A video title",
+        });
+        let video = invidious_video(&value).unwrap();
+        assert_eq!(video.author, "");
+        assert_eq!(video.duration, "");
+    }
+
+    #[test]
+    fn rejects_a_value_missing_the_video_id() {
+        let value = json!({ "title": "No id here" });
+        assert!(invidious_video(&value).is_none());
+    }
+}
@@ -12,11 +12,12 @@ fn main() {
             let api = YTApi::from_header_file(PathBuf::from_str("headers.txt").unwrap().as_path())
                 .await
                 .unwrap();
-            api.search("Carpenter Brut")
-                .await
-                .iter()
-                .for_each(|playlist| {
-                    println!("{:?}", playlist);
-                });
+            let ((videos, playlists), _) = api.search("Carpenter Brut").await.unwrap();
+            videos.iter().for_each(|video| {
+                println!("{:?}", video);
+            });
+            playlists.iter().for_each(|playlist| {
+                println!("{:?}", playlist);
+            });
         });
 }
@@ -0,0 +1,97 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::Value;
+
+use crate::Error;
+
+/// Opt-in capture of raw API responses that an extractor failed to make
+/// sense of, so a maintainer can replay them offline instead of debugging
+/// against an empty search result with no diagnostic. Disabled by
+/// default: normal users pay nothing for it.
+#[derive(Debug, Clone)]
+pub struct ReportConfig {
+    pub dir: PathBuf,
+}
+
+/// Writes `json` to `{config.dir}/{extractor}-{unix_millis}.json` if a
+/// report directory was configured. Called whenever a top-level
+/// extraction (search, playlist browse, ...) comes back with nothing, on
+/// a request that should have produced results.
+pub(crate) fn maybe_report(config: Option<&ReportConfig>, extractor: &str, json: &Value) {
+    let Some(config) = config else {
+        return;
+    };
+    if let Err(e) = write_report(&config.dir, extractor, json) {
+        eprintln!("failed to write {extractor} parse report: {e}");
+    }
+}
+
+fn write_report(dir: &Path, extractor: &str, json: &Value) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let path = dir.join(format!("{extractor}-{timestamp}.json"));
+    std::fs::write(path, serde_json::to_vec_pretty(json).unwrap_or_default())
+}
+
+/// Re-runs a saved report through the named extractor, returning how many
+/// results it produces. Lets a maintainer iterate on a parser offline
+/// against real captured payloads instead of waiting to reproduce API
+/// drift live.
+///
+/// `extractor` must be one of the operation names `maybe_report` is
+/// actually called with (`"search"`, `"browse_playlist"`), since those are
+/// the only tags a saved report can ever be found under.
+pub fn replay_report(path: &Path, extractor: &str) -> Result<usize, Error> {
+    let json: Value = serde_json::from_str(&std::fs::read_to_string(path).map_err(Error::Io)?)
+        .map_err(Error::SerdeJson)?;
+    let count = match extractor {
+        // A search page can yield both videos and playlists, so replay
+        // both extractors and report the total.
+        "search" => {
+            crate::structs::from_json(&json, crate::structs::get_video)?.len()
+                + crate::structs::from_json(&json, crate::structs::get_playlist_search)?.len()
+        }
+        "browse_playlist" => crate::structs::from_json(&json, crate::structs::get_video)?.len(),
+        other => return Err(Error::InvalidReport(format!("unknown extractor `{other}`"))),
+    };
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::replay_report;
+    use crate::Error;
+
+    #[test]
+    fn rejects_an_unknown_extractor() {
+        let path = std::env::temp_dir().join("ytpapi-replay-report-test-unknown.json");
+        std::fs::write(&path, "{}").unwrap();
+        let result = replay_report(&path, "not_a_real_extractor");
+        std::fs::remove_file(&path).ok();
+        assert!(matches!(result, Err(Error::InvalidReport(_))));
+    }
+
+    #[test]
+    fn counts_videos_and_playlists_in_a_replayed_search_report() {
+        let path = std::env::temp_dir().join("ytpapi-replay-report-test-search.json");
+        std::fs::write(&path, "{}").unwrap();
+        let count = replay_report(&path, "search").unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn counts_videos_in_a_replayed_browse_playlist_report() {
+        let path = std::env::temp_dir().join("ytpapi-replay-report-test-browse_playlist.json");
+        std::fs::write(&path, "{}").unwrap();
+        let count = replay_report(&path, "browse_playlist").unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(count, 0);
+    }
+}
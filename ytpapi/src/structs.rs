@@ -171,6 +171,70 @@ pub fn get_videoid(value: &Value) -> Option<String> {
     }
 }
 
+/// Tries to find a continuation token in the json, used to fetch the next
+/// page of a search or playlist browse. Handles both the current
+/// `continuationItemRenderer` shape and the legacy `nextContinuationData`
+/// one.
+pub(crate) fn get_continuation_token(value: &Value) -> Option<String> {
+    match value {
+        Value::Array(e) => e.iter().find_map(get_continuation_token),
+        Value::Object(e) => e
+            .get("continuationItemRenderer")
+            .and_then(|x| x.get("continuationEndpoint"))
+            .and_then(|x| x.get("continuationCommand"))
+            .and_then(|x| x.get("token"))
+            .and_then(Value::as_str)
+            .or_else(|| {
+                e.get("nextContinuationData")
+                    .and_then(|x| x.get("continuation"))
+                    .and_then(Value::as_str)
+            })
+            .map(|x| x.to_string())
+            .or_else(|| e.values().find_map(get_continuation_token)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_continuation_token;
+    use serde_json::json;
+
+    #[test]
+    fn finds_continuation_item_renderer_token() {
+        let json = json!({
+            "contents": [
+                { "musicResponsiveListItemRenderer": {} },
+                {
+                    "continuationItemRenderer": {
+                        "continuationEndpoint": {
+                            "continuationCommand": { "token": "CAIQAA" }
+                        }
+                    }
+                }
+            ]
+        });
+        assert_eq!(get_continuation_token(&json), Some("CAIQAA".to_string()));
+    }
+
+    #[test]
+    fn finds_legacy_next_continuation_data_token() {
+        let json = json!({
+            "nextContinuationData": { "continuation": "legacy_token" }
+        });
+        assert_eq!(
+            get_continuation_token(&json),
+            Some("legacy_token".to_string())
+        );
+    }
+
+    #[test]
+    fn returns_none_without_a_continuation() {
+        let json = json!({ "contents": [{ "title": "no more pages" }] });
+        assert_eq!(get_continuation_token(&json), None);
+    }
+}
+
 /// Tries to extract a video from a json value.
 /// Quite flexible to reduce odds of API change breaking this.
 pub(crate) fn get_video(value: &Value) -> Option<Video> {